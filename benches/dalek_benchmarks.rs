@@ -64,6 +64,19 @@ mod edwards_benches {
         });
     }
 
+    fn vartime_double_var_scalar_mul(c: &mut Criterion) {
+        c.bench_function("Variable-time aA+bB, A and B variable", |bench| {
+            let mut rng = thread_rng();
+            let A = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+            let B = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+            bench.iter_batched(
+                || (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+                |(a, b)| EdwardsPoint::vartime_double_scalar_mul(&a, &A, &b, &B),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
     criterion_group! {
         name = edwards_benches;
         config = Criterion::default();
@@ -73,6 +86,7 @@ mod edwards_benches {
         consttime_fixed_base_scalar_mul,
         consttime_variable_base_scalar_mul,
         vartime_double_base_scalar_mul,
+        vartime_double_var_scalar_mul,
     }
 }
 
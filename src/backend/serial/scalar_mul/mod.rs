@@ -20,6 +20,8 @@ pub mod variable_base;
 
 pub mod vartime_double_base;
 
+pub mod vartime_double_var;
+
 #[cfg(feature = "alloc")]
 pub mod straus;
 
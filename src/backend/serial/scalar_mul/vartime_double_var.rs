@@ -0,0 +1,61 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// Copyright (c) 2016-2019 Isis Lovecruft, Henry de Valence
+// See LICENSE for licensing information.
+//
+// Authors:
+// - Isis Agora Lovecruft <isis@patternsinthevoid.net>
+// - Henry de Valence <hdevalence@hdevalence.ca>
+#![allow(non_snake_case)]
+
+use traits::Identity;
+use scalar::Scalar;
+use edwards::EdwardsPoint;
+use backend::serial::curve_models::{ProjectiveNielsPoint, ProjectivePoint};
+use window::NafLookupTable5;
+
+/// Compute \\(aA + bB\\) in variable time, where \\(A\\) and \\(B\\)
+/// are arbitrary points.
+pub fn mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar, B: &EdwardsPoint) -> EdwardsPoint {
+    let a_naf = a.non_adjacent_form(5);
+    let b_naf = b.non_adjacent_form(5);
+
+    // Find starting index
+    let mut i: usize = 255;
+    for j in (0..256).rev() {
+        i = j;
+        if a_naf[i] != 0 || b_naf[i] != 0 {
+            break;
+        }
+    }
+
+    let table_A = NafLookupTable5::<ProjectiveNielsPoint>::from(A);
+    let table_B = NafLookupTable5::<ProjectiveNielsPoint>::from(B);
+
+    let mut r = ProjectivePoint::identity();
+    loop {
+        let mut t = r.double();
+
+        if a_naf[i] > 0 {
+            t = &t.to_extended() + &table_A.select(a_naf[i] as usize);
+        } else if a_naf[i] < 0 {
+            t = &t.to_extended() - &table_A.select(-a_naf[i] as usize);
+        }
+
+        if b_naf[i] > 0 {
+            t = &t.to_extended() + &table_B.select(b_naf[i] as usize);
+        } else if b_naf[i] < 0 {
+            t = &t.to_extended() - &table_B.select(-b_naf[i] as usize);
+        }
+
+        r = t.to_projective();
+
+        if i == 0 {
+            break;
+        }
+        i -= 1;
+    }
+
+    r.to_extended()
+}
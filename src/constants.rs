@@ -121,6 +121,15 @@ mod test {
         }
     }
 
+    /// Test that the basepoint is a valid point of prime order
+    #[test]
+    fn test_basepoint_is_valid_and_torsion_free() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        assert!(B.is_valid());
+        assert!(B.is_torsion_free());
+        assert!(!B.is_identity());
+    }
+
     /// Test that SQRT_M1 is the positive square root of -1
     #[test]
     fn test_sqrt_minus_one() {
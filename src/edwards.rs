@@ -108,7 +108,7 @@ use subtle::ConstantTimeEq;
 use constants;
 
 use field::FieldElement;
-use scalar::Scalar;
+use scalar::{clamp_integer, Scalar};
 
 use montgomery::MontgomeryPoint;
 
@@ -733,6 +733,15 @@ impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
 }
 
 impl EdwardsPoint {
+    /// Fixed-base scalar multiplication by the Ed25519 basepoint.
+    ///
+    /// This uses the precomputed `constants::ED25519_BASEPOINT_TABLE`, so
+    /// it is equivalent to (and as fast as)
+    /// `&constants::ED25519_BASEPOINT_TABLE * scalar`.
+    pub fn mul_base(scalar: &Scalar) -> EdwardsPoint {
+        &constants::ED25519_BASEPOINT_TABLE * scalar
+    }
+
     /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
     pub fn vartime_double_scalar_mul_basepoint(
         a: &Scalar,
@@ -741,6 +750,17 @@ impl EdwardsPoint {
     ) -> EdwardsPoint {
         scalar_mul::vartime_double_base::mul(a, A, b)
     }
+
+    /// Compute \\(aA + bB\\) in variable time, where \\(A\\) and \\(B\\)
+    /// are arbitrary points.
+    pub fn vartime_double_scalar_mul(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+        B: &EdwardsPoint,
+    ) -> EdwardsPoint {
+        scalar_mul::vartime_double_var::mul(a, A, b, B)
+    }
 }
 
 /// A precomputed table of multiples of a basepoint, for accelerating
@@ -849,6 +869,18 @@ impl EdwardsPoint {
         self.mul_by_pow_2(3)
     }
 
+    /// Clamp `bytes` as in RFC 7748 and multiply `self` by the clamped
+    /// integer.
+    ///
+    /// The clamped integer is a multiple of the cofactor, so the result
+    /// always lies in the prime-order subgroup.
+    pub fn mul_clamped(&self, bytes: &[u8; 32]) -> EdwardsPoint {
+        let n = Scalar {
+            bytes: clamp_integer(*bytes),
+        };
+        self * n
+    }
+
     /// Compute \\([2\^k] P \\) by successive doublings. Requires \\( k > 0 \\).
     pub(crate) fn mul_by_pow_2(&self, k: u32) -> EdwardsPoint {
         debug_assert!( k > 0 );
@@ -1110,6 +1142,27 @@ mod test {
         assert_eq!(aB.compress(), A_TIMES_BASEPOINT);
     }
 
+    /// Test mul_base versus a known scalar multiple from ed25519.py
+    #[test]
+    fn mul_base_vs_ed25519py() {
+        let aB = EdwardsPoint::mul_base(&A_SCALAR);
+        assert_eq!(aB.compress(), A_TIMES_BASEPOINT);
+    }
+
+    /// Test mul_base versus variable-base multiplication of the basepoint
+    #[test]
+    fn mul_base_vs_variable_base() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(
+                EdwardsPoint::mul_base(&s),
+                &constants::ED25519_BASEPOINT_POINT * &s
+            );
+        }
+    }
+
     /// Test that multiplication by the basepoint order kills the basepoint
     #[test]
     fn basepoint_mult_by_basepoint_order() {
@@ -1250,6 +1303,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn mul_clamped_matches_montgomery() {
+        let bytes = A_SCALAR.to_bytes();
+        let P = constants::ED25519_BASEPOINT_POINT.mul_clamped(&bytes);
+
+        assert!(P.is_torsion_free());
+        assert_eq!(
+            P.to_montgomery(),
+            constants::X25519_BASEPOINT.mul_clamped(&bytes)
+        );
+    }
+
     #[test]
     fn scalarmult_extended_point_works_both_ways() {
         let G: EdwardsPoint = constants::ED25519_BASEPOINT_POINT;
@@ -1383,6 +1448,37 @@ mod test {
             assert_eq!(result.compress(), DOUBLE_SCALAR_MULT_RESULT);
         }
 
+        #[test]
+        fn double_scalar_mul_vs_ed25519py() {
+            let A = A_TIMES_BASEPOINT.decompress().unwrap();
+            let result = EdwardsPoint::vartime_double_scalar_mul(
+                &A_SCALAR, &A, &B_SCALAR, &constants::ED25519_BASEPOINT_POINT
+            );
+            assert_eq!(result.compress(), DOUBLE_SCALAR_MULT_RESULT);
+        }
+
+        #[test]
+        fn double_scalar_mul_vs_separate_muls() {
+            let mut rng = rand::thread_rng();
+
+            for _ in 0..100 {
+                let a = Scalar::random(&mut rng);
+                let b = Scalar::random(&mut rng);
+                let A = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+                let B = &Scalar::random(&mut rng) * &constants::ED25519_BASEPOINT_TABLE;
+
+                let result = EdwardsPoint::vartime_double_scalar_mul(&a, &A, &b, &B);
+                assert_eq!(result, &(&A * &a) + &(&B * &b));
+            }
+        }
+
+        #[test]
+        fn double_scalar_mul_same_point() {
+            let A = A_TIMES_BASEPOINT.decompress().unwrap();
+            let result = EdwardsPoint::vartime_double_scalar_mul(&A_SCALAR, &A, &B_SCALAR, &A);
+            assert_eq!(result, &A * &(A_SCALAR + B_SCALAR));
+        }
+
         #[test]
         fn multiscalar_mul_vs_ed25519py() {
             let A = A_TIMES_BASEPOINT.decompress().unwrap();
@@ -53,7 +53,7 @@ use core::ops::{Mul, MulAssign};
 use constants::APLUS2_OVER_FOUR;
 use edwards::{CompressedEdwardsY, EdwardsPoint};
 use field::FieldElement;
-use scalar::Scalar;
+use scalar::{clamp_integer, Scalar};
 
 use traits::Identity;
 
@@ -110,6 +110,18 @@ impl MontgomeryPoint {
         self.0
     }
 
+    /// Given `self` \\( = u\_0(P) \\) and a 32-byte integer `bytes`,
+    /// clamp `bytes` as in RFC 7748 and return \\( u\_0([n]P) \\), where
+    /// \\(n\\) is the clamped integer.
+    ///
+    /// This is the X25519 function, i.e. `X25519(bytes, self)`.
+    pub fn mul_clamped(&self, bytes: &[u8; 32]) -> MontgomeryPoint {
+        let n = Scalar {
+            bytes: clamp_integer(*bytes),
+        };
+        self * n
+    }
+
     /// Attempt to convert to an `EdwardsPoint`, using the supplied
     /// choice of sign for the `EdwardsPoint`.
     ///
@@ -387,6 +399,58 @@ mod test {
         assert_eq!(u18, u18_unred);
     }
 
+    /// Test vectors from RFC 7748, section 5.2.
+    #[test]
+    fn mul_clamped_vs_rfc7748() {
+        let scalar: [u8; 32] = [
+            0xa5, 0x46, 0xe3, 0x6b, 0xf0, 0x52, 0x7c, 0x9d,
+            0x3b, 0x16, 0x15, 0x4b, 0x82, 0x46, 0x5e, 0xdd,
+            0x62, 0x14, 0x4c, 0x0a, 0xc1, 0xfc, 0x5a, 0x18,
+            0x50, 0x6a, 0x22, 0x44, 0xba, 0x44, 0x9a, 0xc4];
+        let u = MontgomeryPoint([
+            0xe6, 0xdb, 0x68, 0x67, 0x58, 0x30, 0x30, 0xdb,
+            0x35, 0x94, 0xc1, 0xa4, 0x24, 0xb1, 0x5f, 0x7c,
+            0x72, 0x66, 0x24, 0xec, 0x26, 0xb3, 0x35, 0x3b,
+            0x10, 0xa9, 0x03, 0xa6, 0xd0, 0xab, 0x1c, 0x4c]);
+        let expected = MontgomeryPoint([
+            0xc3, 0xda, 0x55, 0x37, 0x9d, 0xe9, 0xc6, 0x90,
+            0x8e, 0x94, 0xea, 0x4d, 0xf2, 0x8d, 0x08, 0x4f,
+            0x32, 0xec, 0xcf, 0x03, 0x49, 0x1c, 0x71, 0xf7,
+            0x54, 0xb4, 0x07, 0x55, 0x77, 0xa2, 0x85, 0x52]);
+        assert_eq!(u.mul_clamped(&scalar).to_bytes(), expected.to_bytes());
+
+        // The high bit of this u-coordinate is set and must be ignored.
+        let scalar: [u8; 32] = [
+            0x4b, 0x66, 0xe9, 0xd4, 0xd1, 0xb4, 0x67, 0x3c,
+            0x5a, 0xd2, 0x26, 0x91, 0x95, 0x7d, 0x6a, 0xf5,
+            0xc1, 0x1b, 0x64, 0x21, 0xe0, 0xea, 0x01, 0xd4,
+            0x2c, 0xa4, 0x16, 0x9e, 0x79, 0x18, 0xba, 0x0d];
+        let u = MontgomeryPoint([
+            0xe5, 0x21, 0x0f, 0x12, 0x78, 0x68, 0x11, 0xd3,
+            0xf4, 0xb7, 0x95, 0x9d, 0x05, 0x38, 0xae, 0x2c,
+            0x31, 0xdb, 0xe7, 0x10, 0x6f, 0xc0, 0x3c, 0x3e,
+            0xfc, 0x4c, 0xd5, 0x49, 0xc7, 0x15, 0xa4, 0x93]);
+        let expected = MontgomeryPoint([
+            0x95, 0xcb, 0xde, 0x94, 0x76, 0xe8, 0x90, 0x7d,
+            0x7a, 0xad, 0xe4, 0x5c, 0xb4, 0xb8, 0x73, 0xf8,
+            0x8b, 0x59, 0x5a, 0x68, 0x79, 0x9f, 0xa1, 0x52,
+            0xe6, 0xf8, 0xf7, 0x64, 0x7a, 0xac, 0x79, 0x57]);
+        assert_eq!(u.mul_clamped(&scalar).to_bytes(), expected.to_bytes());
+    }
+
+    #[test]
+    fn mul_clamped_applies_clamp() {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 0b0000_0111;
+
+        let unclamped = &constants::X25519_BASEPOINT * &Scalar::from_bits(bytes);
+        let clamped = constants::X25519_BASEPOINT.mul_clamped(&bytes);
+        let expected = &constants::X25519_BASEPOINT * &Scalar::from_bits(clamp_integer(bytes));
+
+        assert_ne!(clamped, unclamped);
+        assert_eq!(clamped, expected);
+    }
+
     #[test]
     fn montgomery_ladder_matches_edwards_scalarmult() {
         let mut csprng: OsRng = OsRng;
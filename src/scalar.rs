@@ -252,6 +252,19 @@ impl Scalar {
     }
 }
 
+/// Clamp a 256-bit little-endian integer as described in RFC 7748:
+/// clear the low three bits, clear bit 255, and set bit 254.
+///
+/// The result is a multiple of the cofactor \\(8\\) in
+/// \\([2\^{254}, 2\^{255})\\).  It is generally not reduced mod
+/// \\( \ell \\), so it must only be used as raw scalar bits.
+pub(crate) const fn clamp_integer(mut bytes: [u8; 32]) -> [u8; 32] {
+    bytes[0] &= 0b1111_1000;
+    bytes[31] &= 0b0111_1111;
+    bytes[31] |= 0b0100_0000;
+    bytes
+}
+
 impl Debug for Scalar {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "Scalar{{\n\tbytes: {:?},\n}}", &self.bytes)